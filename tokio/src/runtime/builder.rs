@@ -0,0 +1,43 @@
+use crate::task::coop::Budget;
+
+/// Configuration relevant to cooperative scheduling, split out of the main
+/// `runtime::Builder` so it can be tested in isolation.
+pub struct Builder {
+    cooperative_budget: u32,
+}
+
+impl Builder {
+    pub(crate) fn new() -> Builder {
+        Builder {
+            cooperative_budget: Budget::DEFAULT,
+        }
+    }
+
+    /// Configures the initial cooperative budget assigned to each task on every poll.
+    ///
+    /// Tokio's cooperative scheduling forces a task to yield back to the executor once it has
+    /// exhausted this budget, so that it does not starve other tasks or resources running on
+    /// the same runtime. Latency-sensitive servers may want a smaller budget so tasks yield
+    /// sooner; throughput-heavy batch workloads may want a larger one to amortize scheduling
+    /// costs. Defaults to `128` if unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::runtime;
+    ///
+    /// # pub fn main() -> std::io::Result<()> {
+    /// let runtime = runtime::Builder::new_multi_thread()
+    ///     .cooperative_budget(32)
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn cooperative_budget(&mut self, budget: u32) -> &mut Self {
+        self.cooperative_budget = budget;
+        self
+    }
+
+    pub(crate) fn get_cooperative_budget(&self) -> u32 {
+        self.cooperative_budget
+    }
+}
@@ -0,0 +1,36 @@
+use crate::runtime::task::meter::TaskMeter;
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+thread_local! {
+    static CURRENT_TASK_METER: Cell<Option<NonNull<TaskMeter>>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with the [`TaskMeter`] of the task currently being polled on this thread.
+///
+/// Returns `None` if this thread is not currently polling a task.
+pub(crate) fn with_current_task_metrics<R>(f: impl FnOnce(&TaskMeter) -> R) -> Option<R> {
+    CURRENT_TASK_METER.with(|cell| cell.get().map(|meter| f(unsafe { meter.as_ref() })))
+}
+
+/// Installs `meter` as the current task's meter for the duration of the returned guard's
+/// lifetime.
+///
+/// Called by the task harness immediately before polling a task's future, so that per-poll
+/// events (such as a forced yield from [`crate::task::coop::poll_proceed`]) are attributed to
+/// the right task. The previous value, if any, is restored when the guard is dropped.
+#[must_use]
+pub(crate) fn set_current_task_meter(meter: &TaskMeter) -> CurrentTaskMeterGuard {
+    let prev = CURRENT_TASK_METER.with(|cell| cell.replace(Some(NonNull::from(meter))));
+    CurrentTaskMeterGuard { prev }
+}
+
+pub(crate) struct CurrentTaskMeterGuard {
+    prev: Option<NonNull<TaskMeter>>,
+}
+
+impl Drop for CurrentTaskMeterGuard {
+    fn drop(&mut self) {
+        CURRENT_TASK_METER.with(|cell| cell.set(self.prev));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::runtime::builder::Builder;
+use crate::runtime::task::meter::TaskMeter;
+use crate::task::Id;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Handle to the runtime, carrying the configuration needed by tasks while they run.
+///
+/// This only models the subset relevant to cooperative scheduling; the rest of the runtime's
+/// handle lives alongside it.
+pub struct Handle {
+    cooperative_budget: u32,
+    task_meters: Mutex<HashMap<Id, Arc<TaskMeter>>>,
+}
+
+impl Handle {
+    pub(crate) fn new(builder: &Builder) -> Handle {
+        Handle {
+            cooperative_budget: builder.get_cooperative_budget(),
+            task_meters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the initial cooperative budget assigned to tasks spawned on this runtime, as
+    /// configured via [`Builder::cooperative_budget`].
+    pub(crate) fn initial_budget(&self) -> u32 {
+        self.cooperative_budget
+    }
+
+    /// Registers the [`TaskMeter`] of a newly spawned task, so its counters can later be looked
+    /// up by [`Id`].
+    pub(crate) fn register_task_meter(&self, id: Id, meter: Arc<TaskMeter>) {
+        self.task_meters.lock().unwrap().insert(id, meter);
+    }
+
+    /// Removes a task's [`TaskMeter`] once the task completes.
+    pub(crate) fn deregister_task_meter(&self, id: Id) {
+        self.task_meters.lock().unwrap().remove(&id);
+    }
+
+    fn task_meter(&self, id: Id) -> Option<Arc<TaskMeter>> {
+        self.task_meters.lock().unwrap().get(&id).cloned()
+    }
+}
+
+cfg_unstable_metrics! {
+    impl Handle {
+        /// Returns the number of times the task with the given [`Id`] has been forced to yield
+        /// back to the scheduler after exhausting its cooperative budget.
+        ///
+        /// Returns `None` if no task with this id is currently tracked by this runtime, e.g.
+        /// because it has already completed.
+        pub fn task_budget_forced_yield_count(&self, id: Id) -> Option<u64> {
+            self.task_meter(id).map(|meter| meter.budget_forced_yield_count())
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::runtime::context;
+use crate::runtime::handle::Handle;
+use crate::runtime::task::meter::TaskMeter;
+use crate::task::Id;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+
+/// Allocates a [`TaskMeter`] for a task about to be spawned and registers it on `handle`, so its
+/// counters can later be looked up via [`Handle::task_budget_forced_yield_count`].
+///
+/// Called once, at spawn time, before the task is first polled.
+pub(crate) fn register_spawned_task(handle: &Handle, id: Id) -> Arc<TaskMeter> {
+    let meter = Arc::new(TaskMeter::default());
+    handle.register_task_meter(id, meter.clone());
+    meter
+}
+
+/// Removes a completed task's [`TaskMeter`] from `handle`.
+///
+/// Called once the task's future has resolved and it is being dropped from the scheduler.
+pub(crate) fn deregister_completed_task(handle: &Handle, id: Id) {
+    handle.deregister_task_meter(id);
+}
+
+/// Polls `future`, installing `meter` as the current task's meter for the duration of the poll.
+///
+/// This is the hook point the scheduler calls instead of polling a task's future directly, so
+/// that cooperative-budget events observed during the poll (see [`crate::task::coop::poll_proceed`])
+/// are attributed back to this specific task rather than only the scheduler-wide total.
+pub(crate) fn poll_with_task_meter<F>(
+    meter: &TaskMeter,
+    future: Pin<&mut F>,
+    cx: &mut TaskContext<'_>,
+) -> Poll<F::Output>
+where
+    F: Future,
+{
+    let _guard = context::set_current_task_meter(meter);
+    future.poll(cx)
+}
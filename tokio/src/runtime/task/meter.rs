@@ -0,0 +1,27 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Per-task counters exposed through the unstable metrics API.
+///
+/// One `TaskMeter` is allocated per spawned task and registered on the runtime's
+/// [`Handle`](crate::runtime::Handle) under the task's [`Id`](crate::task::Id). It is installed
+/// as the current task's meter on its worker thread for the duration of each poll (see
+/// [`crate::runtime::context::set_current_task_meter`]), so that code running on behalf of the
+/// task, such as [`crate::task::coop::poll_proceed`], can attribute per-poll events back to the
+/// specific task that triggered them.
+#[derive(Debug, Default)]
+pub(crate) struct TaskMeter {
+    budget_forced_yield_count: AtomicU64,
+}
+
+impl TaskMeter {
+    /// Records that this task exhausted its cooperative budget and was forced to yield.
+    pub(crate) fn inc_budget_forced_yield_count(&self) {
+        self.budget_forced_yield_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of times this task has been forced to yield back to the scheduler
+    /// after exhausting its cooperative budget.
+    pub(crate) fn budget_forced_yield_count(&self) -> u64 {
+        self.budget_forced_yield_count.load(Ordering::Relaxed)
+    }
+}
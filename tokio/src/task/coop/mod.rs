@@ -55,8 +55,18 @@
 //! task::coop::unconstrained(fut).await;
 //! # }
 //! ```
+//!
+//! #### configuring the budget
+//!
+//! Each task is given a fixed budget before it has to yield back to the executor.
+//! By default this budget is [`Budget::DEFAULT`], but latency-sensitive applications may want
+//! to yield sooner, while throughput-heavy ones may want to amortize scheduling costs over a
+//! larger budget. Use [`Builder::cooperative_budget`] to configure this when building the
+//! runtime.
+//!
 //! [`poll`]: method@std::future::Future::poll
 //! [`task::unconstrained`]: crate::task::unconstrained()
+//! [`Builder::cooperative_budget`]: crate::runtime::Builder::cooperative_budget()
 
 cfg_rt! {
     mod consume_budget;
@@ -94,7 +104,7 @@ use crate::runtime::context;
 /// Opaque type tracking the amount of "work" a task may still do before
 /// yielding back to the scheduler.
 #[derive(Debug, Copy, Clone)]
-pub(crate) struct Budget(Option<u8>);
+pub(crate) struct Budget(Option<u32>);
 
 pub(crate) struct BudgetDecrement {
     success: bool,
@@ -102,7 +112,8 @@ pub(crate) struct BudgetDecrement {
 }
 
 impl Budget {
-    /// Budget assigned to a task on each poll.
+    /// Budget assigned to a task on each poll if the runtime was not configured with a custom
+    /// value via [`Builder::cooperative_budget`](crate::runtime::Builder::cooperative_budget).
     ///
     /// The value itself is chosen somewhat arbitrarily. It needs to be high
     /// enough to amortize wakeup and scheduling costs, but low enough that we
@@ -111,9 +122,26 @@ impl Budget {
     /// work at all.
     ///
     /// Note that as more yield points are added in the ecosystem, this value
-    /// will probably also have to be raised.
-    const fn initial() -> Budget {
-        Budget(Some(128))
+    /// will probably also have to be raised. Runtimes that want a different tradeoff between
+    /// latency and throughput can override it instead of waiting on a new default.
+    pub(crate) const DEFAULT: u32 = 128;
+
+    /// Budget assigned to a task on each poll.
+    ///
+    /// This reads the initial budget configured on the current runtime's [`Handle`], falling
+    /// back to [`Budget::DEFAULT`] when there is no runtime context (e.g. in tests).
+    ///
+    /// `budget()` is called once per top-level task poll, so this is on tokio's hottest path;
+    /// it is marked `#[inline(always)]`, like its siblings in this file, to keep the added
+    /// `with_current` lookup from showing up as a separate call frame (a `with_current` lookup
+    /// over an already-cached `Handle` is a few ns, well under the cost of the poll it guards).
+    ///
+    /// [`Handle`]: crate::runtime::Handle
+    #[inline(always)]
+    fn initial() -> Budget {
+        Budget(Some(
+            context::with_current(|handle| handle.initial_budget()).unwrap_or(Budget::DEFAULT),
+        ))
     }
 
     /// Returns an unconstrained budget. Operations will not be limited.
@@ -253,16 +281,24 @@ cfg_coop! {
     use std::pin::Pin;
     use std::task::{ready, Context, Poll};
 
+    /// A permit to proceed with polling a cooperative leaf future, acquired from [`poll_proceed`].
+    ///
+    /// Dropping the permit without calling [`Permit::made_progress`] restores the task's budget
+    /// to what it was before [`poll_proceed`] was called, so that a leaf future which returns
+    /// `Poll::Pending` without doing any real work does not spuriously consume the task's budget.
     #[must_use]
-    pub(crate) struct RestoreOnPending(Cell<Budget>);
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub struct Permit(Cell<Budget>);
 
-    impl RestoreOnPending {
-        pub(crate) fn made_progress(&self) {
+    impl Permit {
+        /// Indicates that the future holding this permit made progress, so its consumed budget
+        /// should not be restored when the permit is dropped.
+        pub fn made_progress(&self) {
             self.0.set(Budget::unconstrained());
         }
     }
 
-    impl Drop for RestoreOnPending {
+    impl Drop for Permit {
         fn drop(&mut self) {
             // Don't reset if budget was unconstrained or if we made progress.
             // They are both represented as the remembered budget being unconstrained.
@@ -277,25 +313,29 @@ cfg_coop! {
 
     /// Returns `Poll::Pending` if the current task has exceeded its budget and should yield.
     ///
+    /// This is the low-level primitive behind Tokio's own yield points. Library authors writing
+    /// their own leaf futures (I/O resources, channels, and the like) outside of Tokio can call
+    /// it to participate in the same cooperative scheduling as Tokio's built-in leaf futures.
+    ///
     /// When you call this method, the current budget is decremented. However, to ensure that
     /// progress is made every time a task is polled, the budget is automatically restored to its
-    /// former value if the returned `RestoreOnPending` is dropped. It is the caller's
-    /// responsibility to call `RestoreOnPending::made_progress` if it made progress, to ensure
-    /// that the budget empties appropriately.
+    /// former value if the returned [`Permit`] is dropped. It is the caller's responsibility to
+    /// call [`Permit::made_progress`] if it made progress, to ensure that the budget empties
+    /// appropriately.
     ///
-    /// Note that `RestoreOnPending` restores the budget **as it was before `poll_proceed`**.
-    /// Therefore, if the budget is _further_ adjusted between when `poll_proceed` returns and
-    /// `RestRestoreOnPending` is dropped, those adjustments are erased unless the caller indicates
-    /// that progress was made.
+    /// Note that the permit restores the budget **as it was before `poll_proceed`**. Therefore,
+    /// if the budget is _further_ adjusted between when `poll_proceed` returns and the permit is
+    /// dropped, those adjustments are erased unless the caller indicates that progress was made.
     #[inline]
-    pub(crate) fn poll_proceed(cx: &mut Context<'_>) -> Poll<RestoreOnPending> {
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub fn poll_proceed(cx: &mut Context<'_>) -> Poll<Permit> {
         context::budget(|cell| {
             let mut budget = cell.get();
 
             let decrement = budget.decrement();
 
             if decrement.success {
-                let restore = RestoreOnPending(Cell::new(cell.get()));
+                let restore = Permit(Cell::new(cell.get()));
                 cell.set(budget);
 
                 // avoid double counting
@@ -308,7 +348,7 @@ cfg_coop! {
                 register_waker(cx);
                 Poll::Pending
             }
-        }).unwrap_or(Poll::Ready(RestoreOnPending(Cell::new(Budget::unconstrained()))))
+        }).unwrap_or(Poll::Ready(Permit(Cell::new(Budget::unconstrained()))))
     }
 
     /// Returns `Poll::Ready` if the current task has budget to consume, and `Poll::Pending` otherwise.
@@ -326,6 +366,61 @@ cfg_coop! {
         }
     }
 
+    /// Returns a future that resolves once the current task's cooperative budget has run out.
+    ///
+    /// This does not consume any budget; it only reports when the budget reaches zero. It is
+    /// intended to be used as a `select!`/`join!` arm alongside the actual work a task is doing,
+    /// so that application code can proactively checkpoint or flush work and yield cooperatively,
+    /// rather than being forced to yield mid-operation:
+    ///
+    /// ```
+    /// use tokio::task::coop;
+    ///
+    /// # async fn process_one() {}
+    /// # async fn flush() {}
+    /// # async fn do_some_other_work() {}
+    /// # async fn dox() {
+    /// loop {
+    ///     tokio::select! {
+    ///         _ = coop::budget_depleted() => {
+    ///             flush().await;
+    ///             tokio::task::yield_now().await;
+    ///         }
+    ///         _ = process_one() => {}
+    ///         else => break,
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub fn budget_depleted() -> BudgetDepleted {
+        BudgetDepleted { _p: () }
+    }
+
+    /// Future returned by [`budget_depleted`].
+    #[must_use = "futures do nothing unless polled"]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub struct BudgetDepleted {
+        _p: (),
+    }
+
+    impl Future for BudgetDepleted {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            match poll_budget_available(cx) {
+                // Budget is still available. `poll_budget_available` does not register a waker
+                // in this branch, so simply stay pending until the surrounding `select!`/`join!`
+                // polls us again alongside the real work.
+                Poll::Ready(()) => Poll::Pending,
+                // No budget left: `poll_budget_available` has already registered a waker to be
+                // notified once the budget resets, so the task should yield now.
+                Poll::Pending => Poll::Ready(()),
+            }
+        }
+    }
+
     cfg_rt! {
         cfg_unstable_metrics! {
             #[inline(always)]
@@ -333,6 +428,13 @@ cfg_coop! {
                 let _ = context::with_current(|handle| {
                     handle.scheduler_metrics().inc_budget_forced_yield_count();
                 });
+
+                // Also record the forced yield against the specific task that triggered it, so
+                // that operators can find the individual tasks that keep exhausting their budget
+                // rather than only observing the scheduler-wide total.
+                let _ = context::with_current_task_metrics(|metrics| {
+                    metrics.inc_budget_forced_yield_count();
+                });
             }
         }
 
@@ -382,13 +484,14 @@ cfg_coop! {
     pin_project! {
         /// Future wrapper to ensure cooperative scheduling.
         ///
-        /// When being polled `poll_proceed` is called before the inner future is polled to check
-        /// if the inner future has exceeded its budget. If the inner future resolves, this will
-        /// automatically call `RestoreOnPending::made_progress` before resolving this future with
-        /// the result of the inner one. If polling the inner future is pending, polling this future
-        /// type will also return a `Poll::Pending`.
+        /// When being polled, [`poll_proceed`] is called before the inner future is polled to
+        /// check if the inner future has exceeded its budget. If the inner future resolves, this
+        /// will automatically call [`Permit::made_progress`] before resolving this future with
+        /// the result of the inner one. If polling the inner future is pending, polling this
+        /// future type will also return a `Poll::Pending`.
         #[must_use = "futures do nothing unless polled"]
-        pub(crate) struct Coop<F: Future> {
+        #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+        pub struct Coop<F: Future> {
             #[pin]
             pub(crate) fut: F,
         }
@@ -410,10 +513,14 @@ cfg_coop! {
     }
 
     /// Run a future with a budget constraint for cooperative scheduling.
+    ///
     /// If the future exceeds its budget while being polled, control is yielded back to the
-    /// runtime.
+    /// runtime. This is the same mechanism Tokio's own leaf futures (I/O, channels, `sleep`, ...)
+    /// use internally, made available so that library authors can wrap their own leaf futures
+    /// without calling [`poll_proceed`] directly.
     #[inline]
-    pub(crate) fn cooperative<F: Future>(fut: F) -> Coop<F> {
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub fn cooperative<F: Future>(fut: F) -> Coop<F> {
         Coop { fut }
     }
 }
@@ -496,4 +603,125 @@ mod test {
             assert_pending!(task.poll());
         });
     }
+
+    #[test]
+    fn configured_budget_bounds_polls_before_forced_yield() {
+        use crate::runtime::{Builder, Handle};
+        use tokio_test::*;
+
+        for configured in [1u32, 4, 64] {
+            let mut builder = Builder::new();
+            builder.cooperative_budget(configured);
+            let handle = Handle::new(&builder);
+
+            // `Budget::initial` normally reads this through `context::with_current`; seed the
+            // thread-local directly with what the configured `Handle` reports, since there is no
+            // runtime to enter in this test.
+            budget(|| {
+                context::budget(|cell| cell.set(Budget(Some(handle.initial_budget()))));
+
+                let mut polls_before_yield = 0;
+                loop {
+                    let coop = assert_ready!(task::spawn(()).enter(|cx, _| poll_proceed(cx)));
+                    polls_before_yield += 1;
+                    coop.made_progress();
+                    if !get().has_remaining() {
+                        break;
+                    }
+                }
+
+                assert_eq!(polls_before_yield, configured);
+            });
+        }
+    }
+
+    #[test]
+    fn cooperative_wrapper_yields_once_budget_is_exhausted() {
+        use std::future::poll_fn;
+        use tokio_test::*;
+
+        budget(|| {
+            let n = get().0.unwrap();
+
+            for _ in 0..n {
+                let mut task = task::spawn(cooperative(poll_fn(|_cx| Poll::<()>::Ready(()))));
+                assert_ready!(task.poll());
+            }
+
+            // Budget is now exhausted: the next `cooperative`-wrapped future must be forced to
+            // yield even though its inner future is always ready.
+            let mut task = task::spawn(cooperative(poll_fn(|_cx| Poll::<()>::Ready(()))));
+            assert_pending!(task.poll());
+        });
+    }
+
+    #[cfg(tokio_unstable)]
+    #[test]
+    fn per_task_forced_yield_count_is_attributed_to_the_task() {
+        use crate::runtime::task::harness::{poll_with_task_meter, register_spawned_task};
+        use crate::runtime::{Builder, Handle};
+        use crate::task::Id;
+        use std::future::poll_fn;
+        use std::pin::Pin;
+        use tokio_test::*;
+
+        let mut builder = Builder::new();
+        builder.cooperative_budget(2);
+        let handle = Handle::new(&builder);
+
+        let id = Id::next();
+        let meter = register_spawned_task(&handle, id);
+
+        // No polls have happened yet: the task is registered, but hasn't forced a yield.
+        assert_eq!(handle.task_budget_forced_yield_count(id), Some(0));
+
+        budget(|| {
+            context::budget(|cell| cell.set(Budget(Some(handle.initial_budget()))));
+
+            // A leaf future that keeps proceeding forever; it will be forced to yield once the
+            // task's budget (2) runs out.
+            let mut fut = poll_fn(|cx| {
+                let coop = std::task::ready!(poll_proceed(cx));
+                coop.made_progress();
+                Poll::<()>::Pending
+            });
+
+            let mut task = task::spawn(());
+            // Poll through the whole budget (2 polls succeed) plus one more (forced to yield).
+            for _ in 0..3 {
+                let _ = task.enter(|cx, _| poll_with_task_meter(&meter, Pin::new(&mut fut), cx));
+            }
+        });
+
+        assert_eq!(handle.task_budget_forced_yield_count(id), Some(1));
+        assert_eq!(handle.task_budget_forced_yield_count(Id::next()), None);
+    }
+
+    #[test]
+    fn budget_depleted_does_not_register_a_wake_while_budget_remains() {
+        use tokio_test::*;
+
+        budget(|| {
+            let n = get().0.unwrap();
+            let mut task = task::spawn(budget_depleted());
+
+            // Budget is full: polling repeatedly must stay pending *without* registering a wake
+            // on every poll. The original version of this future called `register_waker`
+            // unconditionally whenever budget was still available, which would have re-armed a
+            // deferred self-wake on every single one of these polls instead of only once the
+            // budget actually ran out.
+            for _ in 0..n {
+                assert_pending!(task.poll());
+            }
+            assert!(!task.is_woken());
+
+            // Exhaust the remaining budget directly, the way a real leaf future would.
+            while get().has_remaining() {
+                let coop = assert_ready!(task::spawn(()).enter(|cx, _| poll_proceed(cx)));
+                coop.made_progress();
+            }
+
+            assert_ready!(task.poll());
+        });
+    }
 }
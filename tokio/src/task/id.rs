@@ -0,0 +1,25 @@
+use std::fmt;
+use std::num::NonZeroU64;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// An opaque ID that uniquely identifies a task relative to all other currently running tasks.
+///
+/// Only the subset needed to key per-task metrics (see
+/// [`crate::runtime::Handle::task_budget_forced_yield_count`]) by id is modeled here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(NonZeroU64);
+
+impl Id {
+    /// Returns a new, never-before-used `Id`.
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        Id(NonZeroU64::new(id).expect("task ID counter overflowed u64"))
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}